@@ -0,0 +1,49 @@
+//! Command-line argument parsing.
+
+use std::path::PathBuf;
+
+use clap::{Parser, ValueEnum};
+
+/// Output mode: colored text for a terminal, or structured data for scripts.
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+    Kv,
+}
+
+/// Display system information, fetch-tool style.
+#[derive(Parser, Debug)]
+#[command(name = "symfetch", version, about)]
+pub struct Cli {
+    /// Comma-separated fields to hide, e.g. `--disable gpu,font`.
+    #[arg(long, value_delimiter = ',')]
+    pub disable: Vec<String>,
+
+    /// Comma-separated field order to print in. Fields left out keep their
+    /// default order and are appended after the ones listed here.
+    #[arg(long, value_delimiter = ',')]
+    pub order: Vec<String>,
+
+    /// Force colored output even when stdout isn't a TTY.
+    #[arg(long, conflicts_with = "no_color")]
+    pub color: bool,
+
+    /// Disable colored output.
+    #[arg(long)]
+    pub no_color: bool,
+
+    /// Config file to use instead of `$XDG_CONFIG_HOME/symfetch/config.toml`.
+    #[arg(long)]
+    pub config: Option<PathBuf>,
+
+    /// List each mounted disk on its own line instead of summing them into
+    /// a single `Storage:` row.
+    #[arg(long)]
+    pub per_disk: bool,
+
+    /// Output format. `json`/`kv` emit raw, unstyled field data instead of
+    /// the colored, human-formatted layout.
+    #[arg(long, value_enum, default_value = "text")]
+    pub format: Format,
+}
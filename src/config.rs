@@ -0,0 +1,76 @@
+//! Optional config file letting users enable/disable fields and override
+//! their label or accent color. Loaded from
+//! `$XDG_CONFIG_HOME/symfetch/config.toml` by default, e.g.:
+//!
+//! ```toml
+//! [fields.gpu]
+//! enabled = false
+//!
+//! [fields.os]
+//! label = "system:"
+//! color = "magenta"
+//! ```
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::field::{parse_color, FieldOverride};
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {
+    #[serde(default)]
+    pub fields: HashMap<String, FieldConfig>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct FieldConfig {
+    pub enabled: Option<bool>,
+    pub label: Option<String>,
+    pub color: Option<String>,
+}
+
+impl Config {
+    /// Loads `path`, or the default XDG location when `path` is `None`.
+    /// Missing or unparsable config files fall back to the defaults rather
+    /// than failing the whole run.
+    pub fn load(path: Option<&Path>) -> Self {
+        let path = match path {
+            Some(path) => path.to_path_buf(),
+            None => Self::default_path(),
+        };
+        std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|contents| toml::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    fn default_path() -> PathBuf {
+        let config_home = std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+            .unwrap_or_else(|| PathBuf::from(".config"));
+        config_home.join("symfetch").join("config.toml")
+    }
+
+    /// Whether `key` is enabled, defaulting to `true` when unconfigured.
+    pub fn is_enabled(&self, key: &str) -> bool {
+        self.fields.get(key).and_then(|f| f.enabled).unwrap_or(true)
+    }
+
+    /// Converts the raw `[fields.*]` table into the label/color overrides
+    /// `SystemInfo::render` applies when printing each row.
+    pub fn field_overrides(&self) -> HashMap<String, FieldOverride> {
+        self.fields
+            .iter()
+            .map(|(key, cfg)| {
+                let overrides = FieldOverride {
+                    label: cfg.label.clone(),
+                    color: cfg.color.as_deref().and_then(parse_color),
+                };
+                (key.clone(), overrides)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,215 @@
+//! Display/resolution detection on X11 and Wayland.
+//!
+//! Both backends are optional feature flags — `use_xlib` for X11 (queries
+//! RandR via `xrandr --query`) and `use_wayland` for Wayland (`wlr-randr`,
+//! falling back to sysfs DRM connector info for compositors that don't ship
+//! it) — so the dependency stays out of builds that don't need it.
+
+use std::env;
+
+/// Detects connected displays and their mode, e.g.
+/// `"DP-1: 2560x1440@144Hz, HDMI-1: 1920x1080@60Hz"`. Falls back to `"1"`
+/// when the relevant backend isn't compiled in or nothing could be detected.
+pub fn detect() -> String {
+    let outputs = if is_wayland() {
+        detect_wayland()
+    } else {
+        detect_x11()
+    };
+
+    if outputs.is_empty() {
+        "1".to_string()
+    } else {
+        outputs.join(", ")
+    }
+}
+
+fn is_wayland() -> bool {
+    env::var_os("WAYLAND_DISPLAY").is_some()
+}
+
+#[cfg(feature = "use_xlib")]
+fn detect_x11() -> Vec<String> {
+    x11::detect()
+}
+
+#[cfg(not(feature = "use_xlib"))]
+fn detect_x11() -> Vec<String> {
+    Vec::new()
+}
+
+#[cfg(feature = "use_wayland")]
+fn detect_wayland() -> Vec<String> {
+    wayland::detect()
+}
+
+#[cfg(not(feature = "use_wayland"))]
+fn detect_wayland() -> Vec<String> {
+    Vec::new()
+}
+
+#[cfg(feature = "use_xlib")]
+mod x11 {
+    use std::process::Command;
+
+    /// Parses `xrandr --query` output, keeping connected outputs' active
+    /// (`*`-marked) mode.
+    pub fn detect() -> Vec<String> {
+        let Ok(output) = Command::new("xrandr").arg("--query").output() else {
+            return Vec::new();
+        };
+        let Ok(text) = String::from_utf8(output.stdout) else {
+            return Vec::new();
+        };
+
+        let mut displays = Vec::new();
+        let mut current_output: Option<String> = None;
+        for line in text.lines() {
+            if !line.starts_with(' ') && !line.starts_with('\t') {
+                current_output = line
+                    .contains(" connected")
+                    .then(|| line.split_whitespace().next().unwrap_or_default().to_string());
+                continue;
+            }
+            if let Some(output) = current_output.take() {
+                if let Some(mode) = parse_mode_line(line) {
+                    displays.push(format!("{output}: {mode}"));
+                } else {
+                    current_output = Some(output);
+                }
+            }
+        }
+        displays
+    }
+
+    /// A mode line looks like `   2560x1440     143.97*+  119.88`; the `*`
+    /// marks the active refresh rate.
+    fn parse_mode_line(line: &str) -> Option<String> {
+        let mut fields = line.split_whitespace();
+        let resolution = fields.next()?;
+        if !resolution.contains('x') {
+            return None;
+        }
+        let rate = fields.find(|f| f.contains('*'))?;
+        let hz: f64 = rate
+            .trim_matches(|c: char| !c.is_ascii_digit() && c != '.')
+            .parse()
+            .ok()?;
+        Some(format!("{resolution}@{}Hz", hz.round() as u64))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn parses_the_active_mode_line() {
+            assert_eq!(
+                parse_mode_line("   2560x1440     143.97*+  119.88"),
+                Some("2560x1440@144Hz".to_string())
+            );
+        }
+
+        #[test]
+        fn ignores_a_mode_line_with_no_active_rate() {
+            assert_eq!(parse_mode_line("   2560x1440     143.97  119.88"), None);
+        }
+
+        #[test]
+        fn ignores_a_non_mode_line() {
+            assert_eq!(parse_mode_line("   h: width  2560 start 2608 end 2640 total 2720"), None);
+        }
+    }
+}
+
+#[cfg(feature = "use_wayland")]
+mod wayland {
+    use std::fs;
+    use std::process::Command;
+
+    pub fn detect() -> Vec<String> {
+        let via_compositor = detect_via_wlr_randr();
+        if !via_compositor.is_empty() {
+            via_compositor
+        } else {
+            detect_via_drm()
+        }
+    }
+
+    /// Parses `wlr-randr` output (sway, river, and other wlroots
+    /// compositors ship this tool).
+    fn detect_via_wlr_randr() -> Vec<String> {
+        let Ok(output) = Command::new("wlr-randr").output() else {
+            return Vec::new();
+        };
+        let Ok(text) = String::from_utf8(output.stdout) else {
+            return Vec::new();
+        };
+
+        let mut displays = Vec::new();
+        let mut current_output: Option<String> = None;
+        for line in text.lines() {
+            if !line.starts_with(' ') && !line.trim().is_empty() {
+                current_output = line.split_whitespace().next().map(str::to_string);
+                continue;
+            }
+            let trimmed = line.trim();
+            let Some(mode) = trimmed.strip_prefix("Current mode: ") else {
+                continue;
+            };
+            if let (Some(output), Some(res_hz)) = (&current_output, parse_wlr_mode(mode)) {
+                displays.push(format!("{output}: {res_hz}"));
+            }
+        }
+        displays
+    }
+
+    /// `mode` looks like `2560x1440 px, 143.912003 Hz`.
+    fn parse_wlr_mode(mode: &str) -> Option<String> {
+        let (resolution, rest) = mode.split_once("px")?;
+        let hz = rest.split_whitespace().find_map(|f| f.parse::<f64>().ok())?;
+        Some(format!("{}@{}Hz", resolution.trim(), hz.round() as u64))
+    }
+
+    /// Falls back to sysfs DRM connector info when no compositor tool is
+    /// available; this reports the first mode listed in each enabled
+    /// connector's `modes` file, with no refresh rate.
+    fn detect_via_drm() -> Vec<String> {
+        let Ok(entries) = fs::read_dir("/sys/class/drm") else {
+            return Vec::new();
+        };
+        let mut displays = Vec::new();
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if fs::read_to_string(path.join("enabled")).unwrap_or_default().trim() != "enabled" {
+                continue;
+            }
+            let Ok(modes) = fs::read_to_string(path.join("modes")) else {
+                continue;
+            };
+            let Some(resolution) = modes.lines().next() else {
+                continue;
+            };
+            displays.push(format!("{}: {resolution}", entry.file_name().to_string_lossy()));
+        }
+        displays
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn parses_resolution_and_refresh_rate() {
+            assert_eq!(
+                parse_wlr_mode("2560x1440 px, 143.912003 Hz"),
+                Some("2560x1440@144Hz".to_string())
+            );
+        }
+
+        #[test]
+        fn returns_none_without_a_px_marker() {
+            assert_eq!(parse_wlr_mode("2560x1440, 143.912003 Hz"), None);
+        }
+    }
+}
@@ -0,0 +1,189 @@
+//! Distro identification, used to pick matching ASCII art and an accent
+//! color for the header and field labels.
+
+use std::fs;
+
+use colored::Color;
+use sysinfo::System;
+
+use crate::logo::Logo;
+
+/// Normalized identity of the running OS, independent of the exact
+/// `PRETTY_NAME` string any particular release happens to report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Distro {
+    Arch,
+    Debian,
+    Ubuntu,
+    Fedora,
+    Macos,
+    Windows,
+    Bsd,
+    /// Recognized as Linux, but not one of the distros we have art for.
+    Linux,
+    Unknown,
+}
+
+impl Distro {
+    /// Detects the running distro. On Linux this parses `/etc/os-release`;
+    /// everywhere else it falls back to `System::long_os_version()`.
+    pub fn detect() -> Self {
+        match fs::read_to_string("/etc/os-release") {
+            Ok(contents) => Self::from_os_release(&contents),
+            Err(_) => Self::from_text(&System::long_os_version().unwrap_or_default()),
+        }
+    }
+
+    fn from_os_release(contents: &str) -> Self {
+        let mut id = String::new();
+        let mut id_like = String::new();
+        let mut pretty_name = String::new();
+        for line in contents.lines() {
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let value = value.trim_matches('"').to_string();
+            match key {
+                "ID" => id = value,
+                "ID_LIKE" => id_like = value,
+                "PRETTY_NAME" => pretty_name = value,
+                _ => {}
+            }
+        }
+
+        Self::from_id(&id)
+            .or_else(|| id_like.split_whitespace().find_map(Self::from_id))
+            .unwrap_or_else(|| Self::from_text(&pretty_name))
+    }
+
+    fn from_id(id: &str) -> Option<Self> {
+        match id {
+            "arch" | "archarm" => Some(Self::Arch),
+            "debian" => Some(Self::Debian),
+            "ubuntu" => Some(Self::Ubuntu),
+            "fedora" => Some(Self::Fedora),
+            _ => None,
+        }
+    }
+
+    fn from_text(text: &str) -> Self {
+        let text = text.to_lowercase();
+        if text.contains("arch") {
+            Self::Arch
+        } else if text.contains("ubuntu") {
+            Self::Ubuntu
+        } else if text.contains("debian") {
+            Self::Debian
+        } else if text.contains("fedora") {
+            Self::Fedora
+        } else if text.contains("mac os") || text.contains("macos") || text.contains("darwin") {
+            Self::Macos
+        } else if text.contains("windows") {
+            Self::Windows
+        } else if text.contains("bsd") {
+            Self::Bsd
+        } else if text.contains("linux") {
+            Self::Linux
+        } else {
+            Self::Unknown
+        }
+    }
+
+    /// The ASCII logo to print for this distro, falling back to a generic
+    /// one when we don't have dedicated art.
+    pub fn logo(self) -> &'static Logo {
+        match self {
+            Self::Arch => &crate::logo::ARCH,
+            Self::Ubuntu => &crate::logo::UBUNTU,
+            Self::Debian => &crate::logo::DEBIAN,
+            Self::Fedora => &crate::logo::FEDORA,
+            Self::Macos | Self::Windows | Self::Bsd | Self::Linux | Self::Unknown => {
+                &crate::logo::GENERIC
+            }
+        }
+    }
+
+    /// The accent color used for the header and field labels.
+    pub fn accent(self) -> Color {
+        match self {
+            Self::Arch => Color::Cyan,
+            Self::Ubuntu | Self::Debian => Color::Red,
+            Self::Fedora => Color::Blue,
+            Self::Macos => Color::White,
+            Self::Windows => Color::Cyan,
+            Self::Bsd => Color::Red,
+            Self::Linux | Self::Unknown => Color::Yellow,
+        }
+    }
+
+    /// Stable identifier used in machine-readable output.
+    pub fn id(self) -> &'static str {
+        match self {
+            Self::Arch => "arch",
+            Self::Debian => "debian",
+            Self::Ubuntu => "ubuntu",
+            Self::Fedora => "fedora",
+            Self::Macos => "macos",
+            Self::Windows => "windows",
+            Self::Bsd => "bsd",
+            Self::Linux => "linux",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_id_directly() {
+        const OS_RELEASE: &str = "\
+NAME=\"Arch Linux\"
+ID=arch
+PRETTY_NAME=\"Arch Linux\"
+";
+        assert_eq!(Distro::from_os_release(OS_RELEASE), Distro::Arch);
+    }
+
+    #[test]
+    fn falls_back_to_id_like_when_id_is_unrecognized() {
+        const OS_RELEASE: &str = "\
+NAME=\"Linux Mint\"
+ID=linuxmint
+ID_LIKE=\"ubuntu debian\"
+PRETTY_NAME=\"Linux Mint 21\"
+";
+        assert_eq!(Distro::from_os_release(OS_RELEASE), Distro::Ubuntu);
+    }
+
+    #[test]
+    fn falls_back_to_pretty_name_when_id_and_id_like_are_unrecognized() {
+        const OS_RELEASE: &str = "\
+NAME=\"Pop!_OS\"
+ID=pop
+PRETTY_NAME=\"Pop!_OS 22.04 (Ubuntu-based)\"
+";
+        assert_eq!(Distro::from_os_release(OS_RELEASE), Distro::Ubuntu);
+    }
+
+    #[test]
+    fn unrecognized_os_release_falls_back_to_unknown() {
+        const OS_RELEASE: &str = "\
+NAME=\"Solaris\"
+ID=solaris
+PRETTY_NAME=\"Oracle Solaris\"
+";
+        assert_eq!(Distro::from_os_release(OS_RELEASE), Distro::Unknown);
+    }
+
+    #[test]
+    fn from_text_matches_known_substrings() {
+        assert_eq!(Distro::from_text("Fedora Linux 40"), Distro::Fedora);
+        assert_eq!(Distro::from_text("macOS 14.5"), Distro::Macos);
+        assert_eq!(Distro::from_text("Windows 11 Pro"), Distro::Windows);
+        assert_eq!(Distro::from_text("FreeBSD 14.0"), Distro::Bsd);
+        assert_eq!(Distro::from_text("Some Other Linux"), Distro::Linux);
+        assert_eq!(Distro::from_text("Plan 9"), Distro::Unknown);
+    }
+}
@@ -0,0 +1,117 @@
+//! The set of displayable info rows, and the per-field customization
+//! (enabled/disabled, relabeling, recoloring) applied on top of them.
+
+use colored::Color;
+
+use crate::system_info::SystemInfo;
+
+/// One row of output. Order here is the default display order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Os,
+    Uptime,
+    Shell,
+    Displays,
+    WindowManager,
+    Terminal,
+    Font,
+    Cpu,
+    Gpu,
+    Memory,
+    Storage,
+}
+
+impl Field {
+    pub const ALL: [Field; 11] = [
+        Field::Os,
+        Field::Uptime,
+        Field::Shell,
+        Field::Displays,
+        Field::WindowManager,
+        Field::Terminal,
+        Field::Font,
+        Field::Cpu,
+        Field::Gpu,
+        Field::Memory,
+        Field::Storage,
+    ];
+
+    /// The stable key used in `--disable`/`--order` and the config file.
+    pub fn key(self) -> &'static str {
+        match self {
+            Field::Os => "os",
+            Field::Uptime => "uptime",
+            Field::Shell => "shell",
+            Field::Displays => "displays",
+            Field::WindowManager => "wm",
+            Field::Terminal => "terminal",
+            Field::Font => "font",
+            Field::Cpu => "cpu",
+            Field::Gpu => "gpu",
+            Field::Memory => "memory",
+            Field::Storage => "storage",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Field> {
+        Field::ALL.into_iter().find(|f| f.key() == key)
+    }
+
+    /// The label printed before the value unless overridden.
+    pub fn default_label(self) -> &'static str {
+        match self {
+            Field::Os => "OS:",
+            Field::Uptime => "Uptime:",
+            Field::Shell => "Shell:",
+            Field::Displays => "Displays:",
+            Field::WindowManager => "WM:",
+            Field::Terminal => "Terminal:",
+            Field::Font => "Font:",
+            Field::Cpu => "CPU:",
+            Field::Gpu => "GPU:",
+            Field::Memory => "Memory:",
+            Field::Storage => "Storage:",
+        }
+    }
+
+    pub fn value(self, info: &SystemInfo) -> &str {
+        match self {
+            Field::Os => &info.os_info,
+            Field::Uptime => &info.uptime,
+            Field::Shell => &info.shell,
+            Field::Displays => &info.displays,
+            Field::WindowManager => &info.window_manager,
+            Field::Terminal => &info.terminal,
+            Field::Font => &info.font,
+            Field::Cpu => &info.cpu,
+            Field::Gpu => &info.gpu,
+            Field::Memory => &info.memory,
+            Field::Storage => &info.storage,
+        }
+    }
+}
+
+/// A per-field customization layered on top of the defaults, sourced from
+/// the config file.
+#[derive(Debug, Clone, Default)]
+pub struct FieldOverride {
+    pub label: Option<String>,
+    pub color: Option<Color>,
+}
+
+/// Parses the color names accepted in the config file (the same eight
+/// `colored` supports via the logo mask: black, red, green, yellow, blue,
+/// magenta, cyan, white).
+pub fn parse_color(name: &str) -> Option<Color> {
+    match name.to_lowercase().as_str() {
+        "black" => Some(Color::Black),
+        "red" => Some(Color::Red),
+        "green" => Some(Color::Green),
+        "yellow" => Some(Color::Yellow),
+        "blue" => Some(Color::Blue),
+        "magenta" => Some(Color::Magenta),
+        "cyan" => Some(Color::Cyan),
+        "white" => Some(Color::White),
+        _ => None,
+    }
+}
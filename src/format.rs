@@ -0,0 +1,59 @@
+//! Human-readable byte formatting shared by the memory and storage fields.
+
+const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+
+/// Formats `bytes` in the largest binary unit (up to TiB) in which the
+/// value, rounded to one decimal place, stays below 1024, e.g. `1.5 GiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    let mut value = bytes as f64;
+    let mut unit = UNITS[0];
+    for &next in &UNITS[1..] {
+        // Compare the value as it will actually be displayed (rounded to
+        // one decimal), not the raw value: otherwise something like
+        // 1048575 bytes rounds up to "1024.0 KiB" after the loop has
+        // already decided to stop, instead of bumping to "1.0 MiB".
+        if (value * 10.0).round() / 10.0 < 1024.0 {
+            break;
+        }
+        value /= 1024.0;
+        unit = next;
+    }
+    format!("{value:.1} {unit}")
+}
+
+/// Formats a used/total pair as `"<used> / <total> (<pct>%)"`, with the
+/// percentage rounded to the nearest whole number, e.g.
+/// `12.4 GiB / 31.2 GiB (40%)`.
+pub fn format_usage(used: u64, total: u64) -> String {
+    let pct = if total == 0 {
+        0
+    } else {
+        ((used as u128 * 100 + total as u128 / 2) / total as u128) as u64
+    };
+    format!("{} / {} ({pct}%)", format_bytes(used), format_bytes(total))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bumps_to_the_next_unit_at_the_rounding_boundary() {
+        assert_eq!(format_bytes(1_048_575), "1.0 MiB");
+        assert_eq!(format_bytes(1024 * 1024 * 1024 - 1), "1.0 GiB");
+    }
+
+    #[test]
+    fn stays_in_unit_below_the_boundary() {
+        assert_eq!(format_bytes(1_048_524), "1023.9 KiB");
+    }
+
+    #[test]
+    fn formats_usage_as_used_over_total_with_percent() {
+        assert_eq!(format_usage(0, 0), "0.0 B / 0.0 B (0%)");
+        assert_eq!(
+            format_usage(1024 * 1024 * 400, 1024 * 1024 * 1000),
+            "400.0 MiB / 1000.0 MiB (40%)"
+        );
+    }
+}
@@ -0,0 +1,262 @@
+//! GPU detection across platforms.
+//!
+//! Linux enumerates PCI display-class devices directly from sysfs and
+//! resolves vendor/device IDs against the system's `pci.ids` database,
+//! falling back to parsing `lspci` when that database isn't installed.
+//! Windows and macOS shell out to the platform's own inventory tools,
+//! since there's no sysfs equivalent there.
+
+use std::process::Command;
+
+/// Returns one human-readable adapter name per detected GPU, e.g.
+/// `["Intel UHD Graphics 630", "NVIDIA GeForce RTX 3070"]` on a laptop with
+/// both integrated and discrete graphics. Empty if none could be detected.
+pub fn detect() -> Vec<String> {
+    #[cfg(target_os = "linux")]
+    {
+        linux::detect()
+    }
+    #[cfg(target_os = "windows")]
+    {
+        windows::detect()
+    }
+    #[cfg(target_os = "macos")]
+    {
+        macos::detect()
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "windows", target_os = "macos")))]
+    {
+        Vec::new()
+    }
+}
+
+/// Runs `command args` and returns its stdout as a string, or `None` if the
+/// command isn't available or exits unsuccessfully.
+#[allow(dead_code)]
+fn run_command(command: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(command).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8(output.stdout).ok()
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use std::fs;
+    use std::path::Path;
+
+    const PCI_IDS_PATH: &str = "/usr/share/hwdata/pci.ids";
+
+    pub fn detect() -> Vec<String> {
+        let mut names = Vec::new();
+        let Ok(entries) = fs::read_dir("/sys/bus/pci/devices") else {
+            return names;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Some(class) = read_trimmed(&path.join("class")) else {
+                continue;
+            };
+            // `class` looks like "0x030000"; the leading "0x03" byte is the
+            // PCI display-controller class (VGA, 3D, display controller…).
+            if !class.starts_with("0x03") {
+                continue;
+            }
+            let vendor = read_trimmed(&path.join("vendor"));
+            let device = read_trimmed(&path.join("device"));
+            let name = match (vendor.as_deref(), device.as_deref()) {
+                (Some(vendor), Some(device)) => {
+                    resolve_name(vendor, device).unwrap_or_else(|| format!("{vendor}:{device}"))
+                }
+                _ => "Unknown GPU".to_string(),
+            };
+            names.push(name);
+        }
+        names
+    }
+
+    fn read_trimmed(path: &Path) -> Option<String> {
+        fs::read_to_string(path).ok().map(|s| s.trim().to_string())
+    }
+
+    /// Resolves `vendor`/`device` (e.g. `0x10de`/`0x2484`) to a human name,
+    /// preferring the local `pci.ids` database and falling back to `lspci`
+    /// when that database isn't installed.
+    fn resolve_name(vendor: &str, device: &str) -> Option<String> {
+        lookup_pci_ids(vendor, device).or_else(|| lookup_lspci(vendor, device))
+    }
+
+    fn lookup_pci_ids(vendor: &str, device: &str) -> Option<String> {
+        let contents = fs::read_to_string(PCI_IDS_PATH).ok()?;
+        parse_pci_ids(&contents, vendor, device)
+    }
+
+    /// Resolves `vendor`/`device` against the contents of a `pci.ids`
+    /// database (vendor lines start at column 0, their devices are indented
+    /// one tab, subdevices two tabs).
+    fn parse_pci_ids(contents: &str, vendor: &str, device: &str) -> Option<String> {
+        let vendor_id = vendor.trim_start_matches("0x");
+        let device_id = device.trim_start_matches("0x");
+
+        let mut lines = contents.lines();
+        let vendor_name = loop {
+            let line = lines.next()?;
+            if let Some(rest) = line.strip_prefix(vendor_id) {
+                break rest.trim().to_string();
+            }
+        };
+
+        for line in lines {
+            if !line.starts_with('\t') {
+                break; // next vendor block started; our device wasn't listed
+            }
+            if line.starts_with("\t\t") {
+                continue; // subdevice entry, not a top-level device
+            }
+            let entry = line.trim_start();
+            if let Some(name) = entry.strip_prefix(device_id) {
+                return Some(format!("{vendor_name} {}", name.trim()));
+            }
+        }
+        None
+    }
+
+    fn lookup_lspci(vendor: &str, device: &str) -> Option<String> {
+        let output = super::run_command("lspci", &["-nn"])?;
+        parse_lspci(&output, vendor, device)
+    }
+
+    /// Finds the `lspci -nn` line tagged with `vendor:device` and returns
+    /// its cleaned-up adapter name.
+    fn parse_lspci(output: &str, vendor: &str, device: &str) -> Option<String> {
+        let needle = format!(
+            "[{}:{}]",
+            vendor.trim_start_matches("0x"),
+            device.trim_start_matches("0x")
+        );
+        output
+            .lines()
+            .find(|line| line.contains(&needle))
+            .and_then(|line| line.split(": ").nth(1))
+            .map(|name| clean_lspci_name(name, &needle))
+    }
+
+    /// Strips the trailing `(rev ..)` and `[vendor:device]` id tag `lspci
+    /// -nn` appends after the human-readable name, e.g. turning `"NVIDIA
+    /// Corporation GA104 [GeForce RTX 3070] [10de:2484] (rev a1)"` into
+    /// `"NVIDIA Corporation GA104 [GeForce RTX 3070]"`.
+    fn clean_lspci_name(name: &str, id_tag: &str) -> String {
+        let mut name = name.trim();
+        if let Some(idx) = name.rfind(" (rev ") {
+            if name.ends_with(')') {
+                name = name[..idx].trim_end();
+            }
+        }
+        if let Some(idx) = name.rfind(id_tag) {
+            name = name[..idx].trim_end();
+        }
+        name.to_string()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        const PCI_IDS: &str = "\
+10de  NVIDIA Corporation
+\t2484  GA104 [GeForce RTX 3070]
+\t\t1458  D1443 GeForce RTX 3070
+8086  Intel Corporation
+\t9bc5  CometLake-U GT2 [UHD Graphics]
+";
+
+        #[test]
+        fn parses_a_known_vendor_and_device() {
+            assert_eq!(
+                parse_pci_ids(PCI_IDS, "0x10de", "0x2484"),
+                Some("NVIDIA Corporation GA104 [GeForce RTX 3070]".to_string())
+            );
+        }
+
+        #[test]
+        fn skips_subdevice_lines() {
+            // 1458 only appears as a two-tab subdevice of 2484, never as a
+            // top-level device of its own.
+            assert_eq!(parse_pci_ids(PCI_IDS, "0x10de", "0x1458"), None);
+        }
+
+        #[test]
+        fn returns_none_for_an_unknown_vendor() {
+            assert_eq!(parse_pci_ids(PCI_IDS, "0x1234", "0x0000"), None);
+        }
+
+        #[test]
+        fn returns_none_for_a_device_under_the_wrong_vendor() {
+            // 9bc5 belongs to Intel (8086), not NVIDIA (10de).
+            assert_eq!(parse_pci_ids(PCI_IDS, "0x10de", "0x9bc5"), None);
+        }
+
+        const LSPCI_OUTPUT: &str = "\
+00:02.0 VGA compatible controller [0300]: Intel Corporation CometLake-U GT2 [UHD Graphics] [8086:9bc5]
+01:00.0 VGA compatible controller [0300]: NVIDIA Corporation GA104 [GeForce RTX 3070] [10de:2484] (rev a1)
+";
+
+        #[test]
+        fn parses_an_lspci_line_without_a_rev_suffix() {
+            assert_eq!(
+                parse_lspci(LSPCI_OUTPUT, "0x8086", "0x9bc5"),
+                Some("Intel Corporation CometLake-U GT2 [UHD Graphics]".to_string())
+            );
+        }
+
+        #[test]
+        fn strips_the_id_tag_and_rev_suffix() {
+            assert_eq!(
+                parse_lspci(LSPCI_OUTPUT, "0x10de", "0x2484"),
+                Some("NVIDIA Corporation GA104 [GeForce RTX 3070]".to_string())
+            );
+        }
+
+        #[test]
+        fn parse_lspci_returns_none_when_the_tag_is_missing() {
+            assert_eq!(parse_lspci(LSPCI_OUTPUT, "0x1234", "0x5678"), None);
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod windows {
+    pub fn detect() -> Vec<String> {
+        let Some(output) = super::run_command(
+            "powershell",
+            &[
+                "-NoProfile",
+                "-Command",
+                "Get-CimInstance Win32_VideoController | Select-Object -ExpandProperty Name",
+            ],
+        ) else {
+            return Vec::new();
+        };
+        output
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect()
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod macos {
+    pub fn detect() -> Vec<String> {
+        let Some(output) = super::run_command("system_profiler", &["SPDisplaysDataType"]) else {
+            return Vec::new();
+        };
+        output
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("Chipset Model:"))
+            .map(|name| name.trim().to_string())
+            .collect()
+    }
+}
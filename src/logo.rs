@@ -0,0 +1,217 @@
+//! ASCII-art logo rendering.
+//!
+//! A [`Logo`] pairs a plain ASCII-art block with a parallel "color mask" of
+//! the same shape: one mask character per glyph, one art line per color
+//! line. This mirrors the logo-template technique used by other fetch
+//! tools, where a single template can be re-themed just by swapping the
+//! mask. Art and color are stored as matched per-line arrays (rather than
+//! one flat multi-line string) specifically so a line never silently loses
+//! its leading whitespace to Rust's backslash-continuation trick, and so
+//! mismatched line lengths are a simple, testable invariant.
+//!
+//! Mask characters: `k`=black, `r`=red, `g`=green, `y`=yellow, `b`=blue,
+//! `m`=magenta, `c`=cyan, `w`=white, `R`=reset. Any other character leaves
+//! the current color unchanged.
+
+use colored::{Color, Colorize};
+
+/// A logo template: ASCII art plus a same-shaped color mask, one entry per
+/// line. `art[i]` and `colors[i]` must have the same character count.
+pub struct Logo {
+    pub art: &'static [&'static str],
+    pub colors: &'static [&'static str],
+    /// Fixed display width the logo block is padded out to, so info lines
+    /// printed beside it all start at the same horizontal offset.
+    pub width: usize,
+}
+
+fn color_for(code: char) -> Option<Color> {
+    match code {
+        'k' => Some(Color::Black),
+        'r' => Some(Color::Red),
+        'g' => Some(Color::Green),
+        'y' => Some(Color::Yellow),
+        'b' => Some(Color::Blue),
+        'm' => Some(Color::Magenta),
+        'c' => Some(Color::Cyan),
+        'w' => Some(Color::White),
+        _ => None,
+    }
+}
+
+impl Logo {
+    /// Walks each `art`/`colors` line pair in lockstep, wrapping each glyph
+    /// in the color selected by its mask character. Returns one `(colored
+    /// text, visible width)` pair per line; the visible width excludes ANSI
+    /// escapes so callers can pad columns correctly.
+    fn render_rows(&self) -> Vec<(String, usize)> {
+        self.art
+            .iter()
+            .zip(self.colors.iter())
+            .map(|(art_line, color_line)| {
+                let mut line = String::new();
+                let mut visible = 0;
+                let mut color = None;
+                for (glyph, mask) in art_line.chars().zip(color_line.chars()) {
+                    if mask == 'R' {
+                        color = None;
+                    } else if let Some(c) = color_for(mask) {
+                        color = Some(c);
+                    }
+                    match color {
+                        Some(c) => line.push_str(&glyph.to_string().color(c).to_string()),
+                        None => line.push(glyph),
+                    }
+                    visible += 1;
+                }
+                (line, visible)
+            })
+            .collect()
+    }
+
+    /// Zips this logo with `info_lines`, placing the logo on the left. Each
+    /// logo row is padded to `width` so the info column lines up, and
+    /// whichever side has fewer rows is padded with blanks for the rest.
+    pub fn beside(&self, info_lines: &[String]) -> Vec<String> {
+        let logo_rows = self.render_rows();
+        let rows = logo_rows.len().max(info_lines.len());
+        let blank_logo = " ".repeat(self.width);
+
+        (0..rows)
+            .map(|i| {
+                let left = match logo_rows.get(i) {
+                    Some((text, visible)) => {
+                        format!("{}{}", text, " ".repeat(self.width.saturating_sub(*visible)))
+                    }
+                    None => blank_logo.clone(),
+                };
+                let right = info_lines.get(i).map(String::as_str).unwrap_or("");
+                format!("{left}  {right}")
+            })
+            .collect()
+    }
+}
+
+/// Generic fallback logo, used when no distro-specific art is available.
+pub const GENERIC: Logo = Logo {
+    art: &[
+        "  .---.",
+        " /     \\",
+        "|   o   |",
+        " \\     /",
+        "  '---'",
+    ],
+    colors: &[
+        "RRRRRRR",
+        "RRRRRRRR",
+        "RRRRwRRRR",
+        "RRRRRRRR",
+        "RRRRRRR",
+    ],
+    width: 11,
+};
+
+/// Arch Linux logo, accented in cyan to match the distro's branding.
+pub const ARCH: Logo = Logo {
+    art: &[
+        "    /\\",
+        "   /  \\",
+        "  /    \\",
+        " /  []  \\",
+        "/________\\",
+    ],
+    colors: &[
+        "RRRRcc",
+        "RRRcRRc",
+        "RRcRRRRc",
+        "RcRRccRRc",
+        "cccccccccc",
+    ],
+    width: 10,
+};
+
+/// Ubuntu logo, accented in orange (approximated with red).
+pub const UBUNTU: Logo = Logo {
+    art: &[
+        "   _____",
+        "  /     \\",
+        " | () () |",
+        "  \\_____/",
+    ],
+    colors: &[
+        "RRRrrrrr",
+        "RRrRRRRRr",
+        "RrRrrRrrRr",
+        "RRrrrrrrr",
+    ],
+    width: 10,
+};
+
+/// Debian logo, accented in red.
+pub const DEBIAN: Logo = Logo {
+    art: &[
+        "   _____",
+        "  /  _  \\",
+        " | ( (   |",
+        "  \\_____/",
+    ],
+    colors: &[
+        "RRRrrrrr",
+        "RRrRRrRRr",
+        "RrRrRrRRRr",
+        "RRrrrrrrr",
+    ],
+    width: 10,
+};
+
+/// Fedora logo, accented in blue.
+pub const FEDORA: Logo = Logo {
+    art: &[
+        "   _____",
+        "  /  _  \\",
+        " | (_) ( |",
+        "  \\_____/",
+    ],
+    colors: &[
+        "RRRbbbbb",
+        "RRbRRbRRb",
+        "RbRbbbRbRb",
+        "RRbbbbbbb",
+    ],
+    width: 10,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [&Logo; 5] = [&GENERIC, &ARCH, &UBUNTU, &DEBIAN, &FEDORA];
+
+    #[test]
+    fn art_and_colors_are_line_for_line_the_same_shape() {
+        for logo in ALL {
+            assert_eq!(
+                logo.art.len(),
+                logo.colors.len(),
+                "art/colors line count mismatch"
+            );
+            for (art_line, color_line) in logo.art.iter().zip(logo.colors.iter()) {
+                assert_eq!(
+                    art_line.chars().count(),
+                    color_line.chars().count(),
+                    "line length mismatch: {art_line:?} vs {color_line:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn render_rows_preserves_leading_whitespace() {
+        // Regression test: a prior version stored art/colors as single
+        // multi-line string literals using the `"\` continuation, which
+        // silently strips the first line's leading whitespace at compile
+        // time and desyncs every subsequent glyph/mask pair.
+        let rows = GENERIC.render_rows();
+        assert_eq!(rows[0].1, GENERIC.art[0].chars().count());
+    }
+}
@@ -0,0 +1,78 @@
+mod cli;
+mod config;
+mod display;
+mod distro;
+mod field;
+mod format;
+mod gpu;
+mod logo;
+mod output;
+mod system_info;
+
+use std::collections::HashSet;
+
+use clap::Parser;
+
+use cli::{Cli, Format};
+use config::Config;
+use field::Field;
+use output::Snapshot;
+use system_info::SystemInfo;
+
+fn main() {
+    let cli = Cli::parse();
+
+    let info = SystemInfo::new();
+
+    if !matches!(cli.format, Format::Text) {
+        let snapshot = Snapshot::from(&info);
+        match cli.format {
+            Format::Json => println!(
+                "{}",
+                snapshot.to_json().expect("Snapshot always serializes")
+            ),
+            Format::Kv => println!("{}", snapshot.to_kv()),
+            Format::Text => unreachable!(),
+        }
+        return;
+    }
+
+    if cli.no_color {
+        colored::control::set_override(false);
+    } else if cli.color {
+        colored::control::set_override(true);
+    }
+
+    let config = Config::load(cli.config.as_deref());
+    let disabled: HashSet<&str> = cli.disable.iter().map(String::as_str).collect();
+    let enabled = |field: &Field| !disabled.contains(field.key()) && config.is_enabled(field.key());
+
+    let fields: Vec<Field> = if cli.order.is_empty() {
+        Field::ALL.into_iter().filter(enabled).collect()
+    } else {
+        let mut ordered: Vec<Field> = Vec::new();
+        for field in cli.order.iter().filter_map(|key| Field::from_key(key)).filter(enabled) {
+            if !ordered.contains(&field) {
+                ordered.push(field);
+            }
+        }
+        for field in Field::ALL.into_iter().filter(enabled) {
+            if !ordered.contains(&field) {
+                ordered.push(field);
+            }
+        }
+        ordered
+    };
+
+    let mut lines = info.render(&fields, &config.field_overrides());
+    if cli.per_disk {
+        if let Some(pos) = fields.iter().position(|f| *f == Field::Storage) {
+            // `render()` prepends the header and a blank line before the
+            // field rows, so the row for `fields[pos]` sits at `pos + 2`.
+            lines.splice(pos + 2..pos + 3, info.disk_lines());
+        }
+    }
+    for line in info.distro.logo().beside(&lines) {
+        println!("{line}");
+    }
+}
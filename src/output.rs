@@ -0,0 +1,123 @@
+//! Machine-readable output (`--format json` / `--format kv`), serializing
+//! the raw values `SystemInfo` computes rather than the human-formatted,
+//! colored strings `render()` produces.
+//!
+//! `kv` is otherwise flat `key=value` lines, but `disks` is a list and gets
+//! serialized as an embedded JSON array (e.g. `disks=[{"mount_point":"/",
+//! ...}]`) rather than exploded into its own keys — a consumer that wants
+//! per-disk data out of `kv` output still needs a JSON parser for that one
+//! field.
+
+use serde::Serialize;
+
+use crate::system_info::SystemInfo;
+
+#[derive(Serialize)]
+pub struct Snapshot {
+    pub user: String,
+    pub hostname: String,
+    pub distro: String,
+    pub os_info: String,
+    pub uptime_secs: u64,
+    pub shell: String,
+    pub displays: String,
+    pub window_manager: String,
+    pub terminal: String,
+    pub font: String,
+    pub cpu: String,
+    pub gpu: String,
+    pub memory_used_bytes: u64,
+    pub memory_total_bytes: u64,
+    pub storage_used_bytes: u64,
+    pub storage_total_bytes: u64,
+    pub disks: Vec<DiskSnapshot>,
+}
+
+/// Used/total space for a single mounted disk, mirroring `DiskUsage`.
+#[derive(Serialize)]
+pub struct DiskSnapshot {
+    pub mount_point: String,
+    pub used: u64,
+    pub total: u64,
+}
+
+impl From<&SystemInfo> for Snapshot {
+    fn from(info: &SystemInfo) -> Self {
+        Snapshot {
+            user: info.user.clone(),
+            hostname: info.hostname.clone(),
+            distro: info.distro.id().to_string(),
+            os_info: info.os_info.clone(),
+            uptime_secs: info.uptime_secs,
+            shell: info.shell.clone(),
+            displays: info.displays.clone(),
+            window_manager: info.window_manager.clone(),
+            terminal: info.terminal.clone(),
+            font: info.font.clone(),
+            cpu: info.cpu.clone(),
+            gpu: info.gpu.clone(),
+            memory_used_bytes: info.memory_used_bytes,
+            memory_total_bytes: info.memory_total_bytes,
+            storage_used_bytes: info.storage_used_bytes,
+            storage_total_bytes: info.storage_total_bytes,
+            disks: info
+                .disks
+                .iter()
+                .map(|disk| DiskSnapshot {
+                    mount_point: disk.mount_point.clone(),
+                    used: disk.used,
+                    total: disk.total,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Snapshot {
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Renders as `key=value` lines, one per field.
+    pub fn to_kv(&self) -> String {
+        let serde_json::Value::Object(fields) =
+            serde_json::to_value(self).expect("Snapshot fields always serialize")
+        else {
+            unreachable!("Snapshot always serializes to a JSON object")
+        };
+        fields
+            .into_iter()
+            .map(|(key, value)| {
+                let value = match value {
+                    serde_json::Value::String(s) => s,
+                    other => other.to_string(),
+                };
+                format!("{key}={value}")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn carries_one_disk_snapshot_per_mounted_disk() {
+        let info = SystemInfo::new();
+        let snapshot = Snapshot::from(&info);
+        assert_eq!(snapshot.disks.len(), info.disks.len());
+        for (snap, disk) in snapshot.disks.iter().zip(info.disks.iter()) {
+            assert_eq!(snap.mount_point, disk.mount_point);
+            assert_eq!(snap.used, disk.used);
+            assert_eq!(snap.total, disk.total);
+        }
+    }
+
+    #[test]
+    fn to_kv_includes_the_disks_field() {
+        let snapshot = Snapshot::from(&SystemInfo::new());
+        assert!(snapshot.to_kv().lines().any(|line| line.starts_with("disks=")));
+    }
+}
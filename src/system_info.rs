@@ -1,14 +1,22 @@
 use chrono::{DateTime, Local};
 use colored::*;
+use std::collections::HashMap;
 use std::env;
 use sysinfo::{Disks, System};
 
+use crate::distro::Distro;
+use crate::field::{Field, FieldOverride};
+use crate::format::format_usage;
+
 pub struct SystemInfo {
     pub user: String,
     pub hostname: String,
     pub datetime: DateTime<Local>,
+    pub distro: Distro,
     pub os_info: String,
     pub uptime: String,
+    /// Raw uptime backing `uptime`, for machine-readable output.
+    pub uptime_secs: u64,
     pub shell: String,
     pub displays: String,
     pub window_manager: String,
@@ -17,7 +25,23 @@ pub struct SystemInfo {
     pub cpu: String,
     pub gpu: String,
     pub memory: String,
+    /// Raw memory bytes backing `memory`, for machine-readable output.
+    pub memory_used_bytes: u64,
+    pub memory_total_bytes: u64,
     pub storage: String,
+    /// Raw storage bytes backing `storage`, for machine-readable output.
+    pub storage_used_bytes: u64,
+    pub storage_total_bytes: u64,
+    /// Per-disk breakdown backing `storage`, for callers that want to list
+    /// mounted disks separately instead of one summed total.
+    pub disks: Vec<DiskUsage>,
+}
+
+/// Used/total space for a single mounted disk.
+pub struct DiskUsage {
+    pub mount_point: String,
+    pub used: u64,
+    pub total: u64,
 }
 
 impl SystemInfo {
@@ -29,6 +53,8 @@ impl SystemInfo {
         let hostname = whoami::hostname().unwrap_or_else(|_| "Unknown".to_string());
         let datetime = Local::now();
 
+        let distro = Distro::detect();
+
         // OS and Kernel info
         let os_info = format!(
             "{} {}",
@@ -53,8 +79,8 @@ impl SystemInfo {
             .unwrap_or("Unknown")
             .to_string();
 
-        // TODO: Displays detection
-        let displays = "1".to_string();
+        // Displays
+        let displays = crate::display::detect();
 
         let window_manager = env::var("XDG_CURRENT_DESKTOP")
             .or_else(|_| env::var("DESKTOP_SESSION"))
@@ -73,38 +99,41 @@ impl SystemInfo {
             "Unknown".to_string()
         };
 
-        // TODO: GPU detection
-        let gpu = "Unknown".to_string();
+        // GPU
+        let gpu_names = crate::gpu::detect();
+        let gpu = if gpu_names.is_empty() {
+            "Unknown".to_string()
+        } else {
+            gpu_names.join(", ")
+        };
 
         // Memory
         let total_memory = sys.total_memory();
         let used_memory = sys.used_memory();
-        let memory = format!(
-            "{}MB / {}MB",
-            used_memory / 1024 / 1024,
-            total_memory / 1024 / 1024
-        );
+        let memory = format_usage(used_memory, total_memory);
 
         // Storage
-        let mut total_storage = 0;
-        let mut used_storage = 0;
-        let disks = Disks::new_with_refreshed_list();
-        for disk in disks.iter() {
-            total_storage += disk.total_space();
-            used_storage += disk.total_space() - disk.available_space();
-        }
-        let storage = format!(
-            "{}GB / {}GB",
-            used_storage / 1024 / 1024 / 1024,
-            total_storage / 1024 / 1024 / 1024
-        );
+        let disk_list = Disks::new_with_refreshed_list();
+        let disks: Vec<DiskUsage> = disk_list
+            .iter()
+            .map(|disk| DiskUsage {
+                mount_point: disk.mount_point().to_string_lossy().to_string(),
+                used: disk.total_space() - disk.available_space(),
+                total: disk.total_space(),
+            })
+            .collect();
+        let total_storage: u64 = disks.iter().map(|d| d.total).sum();
+        let used_storage: u64 = disks.iter().map(|d| d.used).sum();
+        let storage = format_usage(used_storage, total_storage);
 
         SystemInfo {
             user,
             hostname,
             datetime,
+            distro,
             os_info,
             uptime,
+            uptime_secs,
             shell,
             displays,
             window_manager,
@@ -113,35 +142,57 @@ impl SystemInfo {
             cpu: cpu_info,
             gpu,
             memory,
+            memory_used_bytes: used_memory,
+            memory_total_bytes: total_memory,
             storage,
+            storage_used_bytes: used_storage,
+            storage_total_bytes: total_storage,
+            disks,
         }
     }
 
-    pub fn as_vec(&self) -> Vec<String> {
-        let mut lines = Vec::new();
-        // Header
-        lines.push(format!(
-            "{}@{} ({})",
-            self.user.bold().cyan(),
-            self.hostname.bold().cyan(),
-            self.datetime.format("%m/%d/%y %H:%M").to_string().dimmed()
-        ));
-
-        lines.push(String::new());
-
-        lines.push(format!("{} {}", "OS:".bold().yellow(), self.os_info));
-        lines.push(format!("{} {}", "Uptime:".bold().yellow(), self.uptime));
-        lines.push(format!("{} {}", "Shell:".bold().yellow(), self.shell));
-        lines.push(format!("{} {}", "Displays:".bold().yellow(), self.displays));
-        lines.push(format!("{} {}", "WM:".bold().yellow(), self.window_manager));
-        lines.push(format!("{} {}", "Terminal:".bold().yellow(), self.terminal));
-        lines.push(format!("{} {}", "Font:".bold().yellow(), self.font));
-        lines.push(format!("{} {}", "CPU:".bold().yellow(), self.cpu));
-        lines.push(format!("{} {}", "GPU:".bold().yellow(), self.gpu));
-        lines.push(format!("{} {}", "Memory:".bold().yellow(), self.memory));
-        lines.push(format!("{} {}", "Storage:".bold().yellow(), self.storage));
+    /// Renders `fields`, in the given order, applying any label/color
+    /// `overrides` keyed by `Field::key()`. This is what the CLI's
+    /// `--disable`/`--order`/config-file paths all funnel through.
+    pub fn render(&self, fields: &[Field], overrides: &HashMap<String, FieldOverride>) -> Vec<String> {
+        let accent = self.distro.accent();
+        let mut lines = vec![
+            format!(
+                "{}@{} ({})",
+                self.user.bold().color(accent),
+                self.hostname.bold().color(accent),
+                self.datetime.format("%m/%d/%y %H:%M").to_string().dimmed()
+            ),
+            String::new(),
+        ];
+
+        for &field in fields {
+            let over = overrides.get(field.key());
+            let label = over
+                .and_then(|o| o.label.as_deref())
+                .unwrap_or_else(|| field.default_label());
+            let color = over.and_then(|o| o.color).unwrap_or(accent);
+            lines.push(format!("{} {}", label.bold().color(color), field.value(self)));
+        }
         lines
     }
+
+    /// Renders one colored line per disk, for callers that want mounted
+    /// disks listed separately instead of summed into a single `Storage:`
+    /// row (see `--per-disk`).
+    pub fn disk_lines(&self) -> Vec<String> {
+        let accent = self.distro.accent();
+        self.disks
+            .iter()
+            .map(|disk| {
+                format!(
+                    "{} {}",
+                    format!("{}:", disk.mount_point).bold().color(accent),
+                    format_usage(disk.used, disk.total)
+                )
+            })
+            .collect()
+    }
 }
 
 impl Default for SystemInfo {